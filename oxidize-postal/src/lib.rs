@@ -4,9 +4,11 @@ pub mod postal;
 
 // Import from the postal parsing module
 use postal::python_api::{
-    parse_address, parse_address_to_json, expand_address,
-    expand_address_to_json, normalize_address, download_data
+    parse_address, parse_address_to_json, parse_addresses,
+    parse_address_to_postal_address, validate_address, format_address, expand_address,
+    expand_address_to_json, expand_addresses, normalize_address, download_data
 };
+use postal::runtime::{setup, teardown, is_ready};
 use postal::constants;
 
 #[pymodule]
@@ -14,11 +16,21 @@ fn oxidize_postal(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Core parsing and expansion functions
     m.add_function(wrap_pyfunction!(parse_address, m)?)?;
     m.add_function(wrap_pyfunction!(parse_address_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_addresses, m)?)?;
     m.add_function(wrap_pyfunction!(expand_address, m)?)?;
     m.add_function(wrap_pyfunction!(expand_address_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(expand_addresses, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_address_to_postal_address, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_address, m)?)?;
+    m.add_function(wrap_pyfunction!(format_address, m)?)?;
     m.add_function(wrap_pyfunction!(normalize_address, m)?)?;
     m.add_function(wrap_pyfunction!(download_data, m)?)?;
-    
+
+    // Thread-safe initialization subsystem
+    m.add_function(wrap_pyfunction!(setup, m)?)?;
+    m.add_function(wrap_pyfunction!(teardown, m)?)?;
+    m.add_function(wrap_pyfunction!(is_ready, m)?)?;
+
     // Address component constants
     m.add("ADDRESS_NONE", constants::ADDRESS_NONE)?;
     m.add("ADDRESS_ANY", constants::ADDRESS_ANY)?;
@@ -61,6 +61,7 @@ pub const TOKEN_TYPE_WHITESPACE: &str = "whitespace";
 pub const TOKEN_TYPE_OTHER: &str = "other";
 
 /// Address component types as strings
+pub const COMPONENT_HOUSE: &str = "house";
 pub const COMPONENT_HOUSE_NUMBER: &str = "house_number";
 pub const COMPONENT_ROAD: &str = "road";
 pub const COMPONENT_UNIT: &str = "unit";
@@ -85,6 +86,7 @@ pub const COMPONENT_TOPONYM: &str = "toponym";
 /// Get all available component types
 pub fn get_all_component_types() -> Vec<&'static str> {
     vec![
+        COMPONENT_HOUSE,
         COMPONENT_HOUSE_NUMBER,
         COMPONENT_ROAD,
         COMPONENT_UNIT,
@@ -112,3 +114,64 @@ pub fn get_all_component_types() -> Vec<&'static str> {
 pub fn is_valid_component_type(component_type: &str) -> bool {
     get_all_component_types().contains(&component_type)
 }
+
+/// Map an `ADDRESS_*` bit flag to the component label libpostal uses for it.
+///
+/// Only the labels covered by the `ADDRESS_*` bitmask are listed here; the
+/// remaining `COMPONENT_*` constants (city, state, country, ...) are always
+/// returned since there is no corresponding bit to gate them on.
+pub fn component_label_for_flag(flag: u64) -> Option<&'static str> {
+    match flag {
+        ADDRESS_NAME => Some(COMPONENT_HOUSE),
+        ADDRESS_HOUSE_NUMBER => Some(COMPONENT_HOUSE_NUMBER),
+        ADDRESS_STREET => Some(COMPONENT_ROAD),
+        ADDRESS_UNIT => Some(COMPONENT_UNIT),
+        ADDRESS_LEVEL => Some(COMPONENT_LEVEL),
+        ADDRESS_STAIRCASE => Some(COMPONENT_STAIRCASE),
+        ADDRESS_ENTRANCE => Some(COMPONENT_ENTRANCE),
+        ADDRESS_CATEGORY => Some(COMPONENT_CATEGORY),
+        ADDRESS_NEAR => Some(COMPONENT_NEAR),
+        ADDRESS_TOPONYM => Some(COMPONENT_TOPONYM),
+        ADDRESS_POSTAL_CODE => Some(COMPONENT_POSTCODE),
+        ADDRESS_PO_BOX => Some(COMPONENT_PO_BOX),
+        _ => None,
+    }
+}
+
+/// All `ADDRESS_*` bits that gate a specific component label.
+const GATED_ADDRESS_FLAGS: &[u64] = &[
+    ADDRESS_NAME,
+    ADDRESS_HOUSE_NUMBER,
+    ADDRESS_STREET,
+    ADDRESS_UNIT,
+    ADDRESS_LEVEL,
+    ADDRESS_STAIRCASE,
+    ADDRESS_ENTRANCE,
+    ADDRESS_CATEGORY,
+    ADDRESS_NEAR,
+    ADDRESS_TOPONYM,
+    ADDRESS_POSTAL_CODE,
+    ADDRESS_PO_BOX,
+];
+
+/// Drop entries from a parsed component map whose label is gated by an
+/// `ADDRESS_*` bit that is not set in `components`. Labels with no gating
+/// bit (e.g. `city`, `state`, `country`) always pass through.
+pub fn filter_components<V>(
+    mut parsed: std::collections::HashMap<String, V>,
+    components: u64,
+) -> std::collections::HashMap<String, V> {
+    if components == ADDRESS_ALL {
+        return parsed;
+    }
+
+    for flag in GATED_ADDRESS_FLAGS {
+        if components & flag == 0 {
+            if let Some(label) = component_label_for_flag(*flag) {
+                parsed.remove(label);
+            }
+        }
+    }
+
+    parsed
+}
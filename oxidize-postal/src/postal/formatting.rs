@@ -0,0 +1,188 @@
+//! Country-specific address formatting, the inverse of parsing.
+//!
+//! Walks a Google/chromium-i18n style format string for the target country,
+//! substituting each `%`-token with the matching libpostal component,
+//! skipping tokens whose component is absent and collapsing the empty
+//! lines that leaves behind. Countries with no bundled template fall back
+//! to a generic `house_number road / city state postcode / country` layout.
+
+use std::collections::HashMap;
+
+use crate::postal::constants::{
+    COMPONENT_CITY, COMPONENT_COUNTRY, COMPONENT_HOUSE_NUMBER, COMPONENT_LEVEL,
+    COMPONENT_POSTCODE, COMPONENT_ROAD, COMPONENT_STATE, COMPONENT_SUBURB, COMPONENT_UNIT,
+};
+
+/// `country_code -> format string` using the tokens `%N` (name), `%O`
+/// (organization), `%A` (street address lines), `%C` (city/locality), `%S`
+/// (administrative area), `%Z` (postal code), `%X` (sorting code), `%D`
+/// (dependent locality) and `%n` (line break). There is no `name` or
+/// `organization` component in libpostal's output, so `%N`/`%O` only
+/// render when the caller has added those keys to `components` themselves.
+fn format_template(country_code: &str) -> Option<&'static str> {
+    match country_code.to_uppercase().as_str() {
+        "US" => Some("%N%n%O%n%A%n%C, %S %Z"),
+        "CA" => Some("%N%n%O%n%A%n%C %S %Z"),
+        "GB" => Some("%N%n%O%n%A%n%D%n%C%n%Z"),
+        "DE" => Some("%O%n%N%n%A%n%Z %C"),
+        "FR" => Some("%O%n%N%n%A%n%Z %C"),
+        "JP" => Some("%Z%n%S%C%n%A%n%O%n%N"),
+        "AU" => Some("%O%n%N%n%A%n%C %S %Z"),
+        _ => None,
+    }
+}
+
+fn token_value(components: &HashMap<String, String>, token: char) -> Option<String> {
+    match token {
+        'N' => components.get("name").cloned(),
+        'O' => components.get("organization").cloned(),
+        'A' => {
+            let mut parts = Vec::new();
+            if let Some(house_number) = components.get(COMPONENT_HOUSE_NUMBER) {
+                parts.push(house_number.as_str());
+            }
+            if let Some(road) = components.get(COMPONENT_ROAD) {
+                parts.push(road.as_str());
+            }
+            if let Some(unit) = components.get(COMPONENT_UNIT) {
+                parts.push(unit.as_str());
+            }
+            if let Some(level) = components.get(COMPONENT_LEVEL) {
+                parts.push(level.as_str());
+            }
+            if parts.is_empty() {
+                None
+            } else {
+                Some(parts.join(" "))
+            }
+        }
+        'C' => components.get(COMPONENT_CITY).cloned(),
+        'S' => components.get(COMPONENT_STATE).cloned(),
+        'Z' => components.get(COMPONENT_POSTCODE).cloned(),
+        'X' => components.get("sorting_code").cloned(),
+        'D' => components.get(COMPONENT_SUBURB).cloned(),
+        _ => None,
+    }
+}
+
+/// Render a components map as a format string and collapse the result down
+/// to non-empty lines, joined with `\n`.
+fn render_template(template: &str, components: &HashMap<String, String>) -> String {
+    let mut line = String::new();
+    let mut lines = Vec::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            if let Some(&token) = chars.peek() {
+                chars.next();
+                if token == 'n' {
+                    lines.push(line.trim().to_string());
+                    line = String::new();
+                } else if let Some(value) = token_value(components, token) {
+                    line.push_str(&value);
+                }
+                continue;
+            }
+        }
+        line.push(c);
+    }
+    lines.push(line.trim().to_string());
+
+    lines.into_iter().filter(|l| !l.is_empty()).collect::<Vec<_>>().join("\n")
+}
+
+/// Generic layout used when the country has no bundled template:
+/// `house_number road / city state postcode / country`.
+fn render_fallback(components: &HashMap<String, String>) -> String {
+    let street = [COMPONENT_HOUSE_NUMBER, COMPONENT_ROAD]
+        .iter()
+        .filter_map(|key| components.get(*key))
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let city_line = [COMPONENT_CITY, COMPONENT_STATE, COMPONENT_POSTCODE]
+        .iter()
+        .filter_map(|key| components.get(*key))
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let country = components.get(COMPONENT_COUNTRY).cloned();
+
+    [Some(street), Some(city_line), country]
+        .into_iter()
+        .flatten()
+        .filter(|segment| !segment.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// Format a libpostal component map into a country-ordered postal address.
+pub fn format_address(components: &HashMap<String, String>, country_code: &str) -> String {
+    match format_template(country_code) {
+        Some(template) => render_template(template, components),
+        None => render_fallback(components),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn components(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn a_token_includes_unit_and_level() {
+        let components = components(&[
+            (COMPONENT_HOUSE_NUMBER, "221B"),
+            (COMPONENT_ROAD, "Baker Street"),
+            (COMPONENT_UNIT, "Unit 4"),
+            (COMPONENT_LEVEL, "Floor 2"),
+        ]);
+
+        assert_eq!(
+            token_value(&components, 'A').as_deref(),
+            Some("221B Baker Street Unit 4 Floor 2")
+        );
+    }
+
+    #[test]
+    fn a_token_is_absent_when_no_street_components_are_present() {
+        assert_eq!(token_value(&HashMap::new(), 'A'), None);
+    }
+
+    #[test]
+    fn us_template_renders_city_state_zip_on_one_line() {
+        let components = components(&[
+            (COMPONENT_HOUSE_NUMBER, "1600"),
+            (COMPONENT_ROAD, "Amphitheatre Pkwy"),
+            (COMPONENT_CITY, "Mountain View"),
+            (COMPONENT_STATE, "CA"),
+            (COMPONENT_POSTCODE, "94043"),
+        ]);
+
+        assert_eq!(
+            format_address(&components, "US"),
+            "1600 Amphitheatre Pkwy\nMountain View, CA 94043"
+        );
+    }
+
+    #[test]
+    fn unlisted_country_falls_back_to_generic_layout() {
+        let components = components(&[
+            (COMPONENT_HOUSE_NUMBER, "1"),
+            (COMPONENT_ROAD, "Main St"),
+            (COMPONENT_CITY, "Springfield"),
+            (COMPONENT_COUNTRY, "Freedonia"),
+        ]);
+
+        assert_eq!(format_address(&components, "ZZ"), "1 Main St / Springfield / Freedonia");
+    }
+}
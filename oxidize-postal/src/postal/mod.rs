@@ -0,0 +1,8 @@
+pub mod constants;
+pub mod error;
+pub mod formatting;
+pub mod parser;
+pub mod postal_address;
+pub mod python_api;
+pub mod runtime;
+pub mod validation;
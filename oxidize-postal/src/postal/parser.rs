@@ -2,16 +2,40 @@
 
 use libpostal_rust::{ParseAddressOptions, ExpandAddressOptions, parse_address, expand_address};
 use std::collections::HashMap;
+use crate::postal::constants::{self, ADDRESS_ALL};
 use crate::postal::error::PostalError;
+use crate::postal::runtime;
 
-/// Parse an address string into its component parts
+/// Parse an address string into its component parts using default options
 pub fn parse_address_string(address: &str) -> Result<HashMap<String, String>, PostalError> {
-    parse_address_with_options(address)
+    parse_address_with_options(address, None, None, ADDRESS_ALL)
 }
 
-/// Parse an address string into its component parts
+/// Parse an address string into its component parts, acquiring the runtime
+/// lock for just this one call.
+///
+/// `language` and `country` are optional ISO hints passed straight through to
+/// libpostal to disambiguate multilingual input. `components` is an
+/// `ADDRESS_*` bitmask (see [`crate::postal::constants`]) restricting which
+/// labels are returned; pass `ADDRESS_ALL` to keep everything.
 pub fn parse_address_with_options(
-    address: &str
+    address: &str,
+    language: Option<&str>,
+    country: Option<&str>,
+    components: u64,
+) -> Result<HashMap<String, String>, PostalError> {
+    runtime::with_lock(|| parse_address_locked(address, language, country, components))
+}
+
+/// Same as [`parse_address_with_options`], but assumes the caller is already
+/// holding the runtime lock (see [`crate::postal::runtime::with_lock`]).
+/// Batch callers that parse many addresses in one run should take the lock
+/// once and call this directly rather than re-locking per address.
+pub fn parse_address_locked(
+    address: &str,
+    language: Option<&str>,
+    country: Option<&str>,
+    components: u64,
 ) -> Result<HashMap<String, String>, PostalError> {
     if address.trim().is_empty() {
         return Err(PostalError::InvalidInput {
@@ -20,15 +44,57 @@ pub fn parse_address_with_options(
         });
     }
 
-    let options = ParseAddressOptions::default();
-    
-    parse_address(address, &options).map_err(|e| PostalError::LibpostalError {
+    let options = ParseAddressOptions {
+        language: language.map(str::to_string),
+        country: country.map(str::to_string),
+        ..Default::default()
+    };
+
+    let parsed = parse_address(address, &options).map_err(|e| PostalError::LibpostalError {
         message: format!("Failed to parse address: {}", e),
-    })
+    })?;
+
+    Ok(constants::filter_components(parsed, components))
 }
 
-/// Expand abbreviations in an address string
+/// Expand abbreviations in an address string using default options
 pub fn expand_address_string(address: &str) -> Result<Vec<String>, PostalError> {
+    expand_address_with_options(
+        address,
+        &[],
+        constants::NORMALIZE_DEFAULT_STRING_OPTIONS,
+        constants::NORMALIZE_DEFAULT_TOKEN_OPTIONS,
+    )
+}
+
+/// Expand abbreviations in an address string, acquiring the runtime lock for
+/// just this one call.
+///
+/// `languages` restricts expansion to the given ISO language codes (empty
+/// means let libpostal detect the language itself). `string_options` and
+/// `token_options` are `NORMALIZE_STRING_*` / `NORMALIZE_TOKEN_*` bitmasks
+/// (see [`crate::postal::constants`]) controlling transliteration, accent
+/// stripping, hyphen handling and numeric expansion.
+pub fn expand_address_with_options(
+    address: &str,
+    languages: &[String],
+    string_options: u32,
+    token_options: u32,
+) -> Result<Vec<String>, PostalError> {
+    runtime::with_lock(|| expand_address_locked(address, languages, string_options, token_options))
+}
+
+/// Same as [`expand_address_with_options`], but assumes the caller is
+/// already holding the runtime lock (see
+/// [`crate::postal::runtime::with_lock`]). Batch callers that expand many
+/// addresses in one run should take the lock once and call this directly
+/// rather than re-locking per address.
+pub fn expand_address_locked(
+    address: &str,
+    languages: &[String],
+    string_options: u32,
+    token_options: u32,
+) -> Result<Vec<String>, PostalError> {
     if address.trim().is_empty() {
         return Err(PostalError::InvalidInput {
             message: "Address string is empty or contains only whitespace".to_string(),
@@ -36,11 +102,14 @@ pub fn expand_address_string(address: &str) -> Result<Vec<String>, PostalError>
         });
     }
 
-    let options = ExpandAddressOptions::default();
+    let options = ExpandAddressOptions {
+        languages: languages.to_vec(),
+        string_options,
+        token_options,
+        ..Default::default()
+    };
 
-    expand_address(address, &options).map_err(|e| {
-        PostalError::LibpostalError {
-            message: format!("Failed to expand address: {}", e),
-        }
+    expand_address(address, &options).map_err(|e| PostalError::LibpostalError {
+        message: format!("Failed to expand address: {}", e),
     })
 }
\ No newline at end of file
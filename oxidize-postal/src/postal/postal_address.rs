@@ -0,0 +1,121 @@
+//! Mapping from libpostal's flat component labels to the Google i18n
+//! `type.PostalAddress` schema, which downstream payment and shipping
+//! systems expect instead of libpostal's raw label map.
+
+use std::collections::HashMap;
+
+use crate::postal::constants::{
+    COMPONENT_CITY, COMPONENT_COUNTRY, COMPONENT_HOUSE_NUMBER, COMPONENT_POSTCODE,
+    COMPONENT_ROAD, COMPONENT_STATE, COMPONENT_SUBURB, COMPONENT_UNIT,
+};
+
+/// A Google `type.PostalAddress`-shaped view of a parsed address.
+#[derive(Debug, Default, Clone)]
+pub struct PostalAddress {
+    /// An ISO 3166-1 alpha-2 code where [`normalize_region_code`] could
+    /// resolve one; otherwise libpostal's raw, unvalidated "country" label.
+    pub region_code: Option<String>,
+    pub postal_code: Option<String>,
+    pub administrative_area: Option<String>,
+    pub locality: Option<String>,
+    pub sublocality: Option<String>,
+    pub address_lines: Vec<String>,
+    pub organization: Option<String>,
+    pub recipients: Vec<String>,
+    pub language_code: Option<String>,
+}
+
+/// `country_code -> name variants` libpostal is known to emit for it, for
+/// mapping back onto an ISO 3166-1 alpha-2 code. Covers the same country set
+/// as [`crate::postal::validation`]; anything else falls through to
+/// [`normalize_region_code`]'s raw-value fallback.
+fn country_aliases(country_code: &str) -> Option<&'static [&'static str]> {
+    match country_code {
+        "US" => Some(&["us", "usa", "united states", "united states of america"]),
+        "CA" => Some(&["ca", "canada"]),
+        "GB" => Some(&["gb", "uk", "united kingdom", "great britain"]),
+        "DE" => Some(&["de", "germany", "deutschland"]),
+        "FR" => Some(&["fr", "france"]),
+        "JP" => Some(&["jp", "japan"]),
+        "AU" => Some(&["au", "australia"]),
+        _ => None,
+    }
+}
+
+const KNOWN_REGION_CODES: &[&str] = &["US", "CA", "GB", "DE", "FR", "JP", "AU"];
+
+/// Resolve a `region_code` to an ISO 3166-1 alpha-2 code where possible.
+///
+/// Prefers the caller-supplied `country_hint` (the same ISO hint passed to
+/// `parse_address`) if it's already a known 2-letter code. Failing that,
+/// matches `parsed_country` (libpostal's free-text "country" label, e.g.
+/// "USA" or "United States") against a small alias table. If neither
+/// resolves, falls back to `parsed_country` verbatim — callers should treat
+/// that case as an unverified, non-ISO value rather than a guaranteed code.
+fn normalize_region_code(
+    parsed_country: Option<&str>,
+    country_hint: Option<&str>,
+) -> Option<String> {
+    if let Some(hint) = country_hint {
+        let upper = hint.to_uppercase();
+        if KNOWN_REGION_CODES.contains(&upper.as_str()) {
+            return Some(upper);
+        }
+    }
+
+    let parsed_country = parsed_country?;
+    let lowered = parsed_country.trim().to_lowercase();
+
+    for code in KNOWN_REGION_CODES {
+        if country_aliases(code).is_some_and(|aliases| aliases.contains(&lowered.as_str())) {
+            return Some((*code).to_string());
+        }
+    }
+
+    Some(parsed_country.to_string())
+}
+
+/// Map libpostal's flat component labels onto the Google i18n schema.
+///
+/// Street-level components (`house_number`, `road`, `unit`) collapse into
+/// ordered `address_lines`. libpostal has no recipient or organization
+/// label, so those are left empty for callers to fill in themselves.
+/// `country_hint` is the same ISO hint passed to `parse_address`, if any; it
+/// takes priority over libpostal's parsed "country" label when resolving
+/// `region_code` (see [`normalize_region_code`]).
+pub fn from_components(
+    components: &HashMap<String, String>,
+    language_code: Option<&str>,
+    country_hint: Option<&str>,
+) -> PostalAddress {
+    let mut address_lines = Vec::new();
+
+    let mut street_line = Vec::new();
+    if let Some(house_number) = components.get(COMPONENT_HOUSE_NUMBER) {
+        street_line.push(house_number.clone());
+    }
+    if let Some(road) = components.get(COMPONENT_ROAD) {
+        street_line.push(road.clone());
+    }
+    if !street_line.is_empty() {
+        address_lines.push(street_line.join(" "));
+    }
+    if let Some(unit) = components.get(COMPONENT_UNIT) {
+        address_lines.push(unit.clone());
+    }
+
+    PostalAddress {
+        region_code: normalize_region_code(
+            components.get(COMPONENT_COUNTRY).map(String::as_str),
+            country_hint,
+        ),
+        postal_code: components.get(COMPONENT_POSTCODE).cloned(),
+        administrative_area: components.get(COMPONENT_STATE).cloned(),
+        locality: components.get(COMPONENT_CITY).cloned(),
+        sublocality: components.get(COMPONENT_SUBURB).cloned(),
+        address_lines,
+        organization: None,
+        recipients: Vec::new(),
+        language_code: language_code.map(str::to_string),
+    }
+}
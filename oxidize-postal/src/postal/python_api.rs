@@ -5,11 +5,18 @@
 
 use pyo3::prelude::*;
 use std::collections::HashMap;
+use crate::postal::constants::{
+    ADDRESS_ALL, NORMALIZE_DEFAULT_STRING_OPTIONS, NORMALIZE_DEFAULT_TOKEN_OPTIONS,
+};
 use crate::postal::error::PostalError;
 use crate::postal::parser::{
-    parse_address_string, parse_address_with_options,
-    expand_address_string
+    parse_address_string, parse_address_with_options, parse_address_locked,
+    expand_address_string, expand_address_with_options, expand_address_locked,
 };
+use crate::postal::formatting;
+use crate::postal::postal_address;
+use crate::postal::runtime;
+use crate::postal::validation;
 
 /// Helper macro to reduce boilerplate for functions that release the GIL
 macro_rules! with_gil_released {
@@ -18,6 +25,9 @@ macro_rules! with_gil_released {
     };
 }
 
+/// Per-row failures from a batch call: `(index into the input list, message)`
+type BatchErrors = Vec<(usize, String)>;
+
 /// Helper function to serialize to JSON with consistent error handling
 fn to_json<T: serde::Serialize>(data: &T) -> Result<String, PostalError> {
     serde_json::to_string(data).map_err(|e| {
@@ -28,23 +38,33 @@ fn to_json<T: serde::Serialize>(data: &T) -> Result<String, PostalError> {
 }
 
 /// Parse an address string into its component parts
-/// 
+///
 /// Args:
 ///     address (str): The address string to parse
-///     
+///     language (str, optional): ISO language code hint (e.g. "en")
+///     country (str, optional): ISO country code hint (e.g. "us")
+///     components (int, optional): ADDRESS_* bitmask restricting which
+///         labels are returned. Defaults to ADDRESS_ALL.
+///
 /// Returns:
 ///     dict: A dictionary mapping component names to values
-///     
+///
 /// Raises:
 ///     ValueError: If the address is empty or invalid
 ///     RuntimeError: If libpostal encounters an error
 #[pyfunction]
-#[pyo3(signature = (address))]
+#[pyo3(signature = (address, language=None, country=None, components=None))]
 pub fn parse_address(
-    py: Python<'_>, 
-    address: &str
+    py: Python<'_>,
+    address: &str,
+    language: Option<&str>,
+    country: Option<&str>,
+    components: Option<u64>,
 ) -> PyResult<HashMap<String, String>> {
-    with_gil_released!(py, || parse_address_with_options(address))
+    let components = components.unwrap_or(ADDRESS_ALL);
+    with_gil_released!(py, || parse_address_with_options(
+        address, language, country, components
+    ))
 }
 
 /// Parse an address string and return as JSON string
@@ -66,23 +86,41 @@ pub fn parse_address_to_json(py: Python<'_>, address: &str) -> PyResult<String>
 }
 
 /// Expand abbreviations in an address string
-/// 
+///
 /// Args:
 ///     address (str): The address string to expand
-///     
+///     languages (list[str], optional): ISO language codes to expand with;
+///         defaults to letting libpostal detect the language
+///     string_options (int, optional): NORMALIZE_STRING_* bitmask
+///         controlling transliteration, accent stripping and hyphen
+///         handling. Defaults to NORMALIZE_DEFAULT_STRING_OPTIONS.
+///     token_options (int, optional): NORMALIZE_TOKEN_* bitmask controlling
+///         per-token normalization. Defaults to NORMALIZE_DEFAULT_TOKEN_OPTIONS.
+///
 /// Returns:
 ///     list[str]: List of possible expansions of the address
-///     
+///
 /// Raises:
 ///     ValueError: If the address is empty or invalid
 ///     RuntimeError: If libpostal encounters an error
 #[pyfunction]
-#[pyo3(signature = (address))]
+#[pyo3(signature = (address, languages=None, string_options=None, token_options=None))]
 pub fn expand_address(
-    py: Python<'_>, 
-    address: &str
+    py: Python<'_>,
+    address: &str,
+    languages: Option<Vec<String>>,
+    string_options: Option<u32>,
+    token_options: Option<u32>,
 ) -> PyResult<Vec<String>> {
-    with_gil_released!(py, || expand_address_string(address))
+    let languages = languages.unwrap_or_default();
+    let string_options = string_options.unwrap_or(NORMALIZE_DEFAULT_STRING_OPTIONS);
+    let token_options = token_options.unwrap_or(NORMALIZE_DEFAULT_TOKEN_OPTIONS);
+    with_gil_released!(py, || expand_address_with_options(
+        address,
+        &languages,
+        string_options,
+        token_options
+    ))
 }
 
 /// Expand abbreviations in an address and return as JSON string
@@ -103,6 +141,213 @@ pub fn expand_address_to_json(py: Python<'_>, address: &str) -> PyResult<String>
     to_json(&expanded).map_err(PyErr::from)
 }
 
+/// Parse a batch of address strings, releasing the GIL once for the whole run.
+///
+/// Args:
+///     addresses (list[str]): The address strings to parse
+///     language (str, optional): ISO language code hint applied to every row
+///     country (str, optional): ISO country code hint applied to every row
+///     components (int, optional): ADDRESS_* bitmask restricting which
+///         labels are returned. Defaults to ADDRESS_ALL.
+///     skip_errors (bool): If True, a row that fails to parse is recorded in
+///         the returned error list and yields an empty dict instead of
+///         aborting the whole batch. Defaults to False.
+///
+/// Returns:
+///     tuple[list[dict], list[tuple[int, str]]]: Parsed components per row,
+///     and `(index, message)` pairs for rows that failed when `skip_errors`
+///     is set.
+///
+/// Raises:
+///     ValueError: If an address is empty or invalid and skip_errors is False
+///     RuntimeError: If libpostal encounters an error and skip_errors is False
+#[pyfunction]
+#[pyo3(signature = (addresses, language=None, country=None, components=None, skip_errors=false))]
+pub fn parse_addresses(
+    py: Python<'_>,
+    addresses: Vec<String>,
+    language: Option<&str>,
+    country: Option<&str>,
+    components: Option<u64>,
+    skip_errors: bool,
+) -> PyResult<(Vec<HashMap<String, String>>, BatchErrors)> {
+    let components = components.unwrap_or(ADDRESS_ALL);
+    // Hold the GIL release for the whole batch rather than reacquiring it per
+    // row, and likewise take the runtime lock once for the whole run instead
+    // of once per address (libpostal itself is not reentrant, see the
+    // runtime module) so both costs are amortized across the list.
+    py.allow_threads(|| {
+        runtime::with_lock(|| {
+            let mut results = Vec::with_capacity(addresses.len());
+            let mut errors = Vec::new();
+
+            for (index, address) in addresses.iter().enumerate() {
+                match parse_address_locked(address, language, country, components) {
+                    Ok(parsed) => results.push(parsed),
+                    Err(e) if skip_errors => {
+                        errors.push((index, e.to_string()));
+                        results.push(HashMap::new());
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+
+            Ok((results, errors))
+        })
+    })
+    .map_err(PyErr::from)
+}
+
+/// Expand a batch of address strings, releasing the GIL once for the whole run.
+///
+/// Args:
+///     addresses (list[str]): The address strings to expand
+///     languages (list[str], optional): ISO language codes to expand with;
+///         defaults to letting libpostal detect the language
+///     string_options (int, optional): NORMALIZE_STRING_* bitmask. Defaults
+///         to NORMALIZE_DEFAULT_STRING_OPTIONS.
+///     token_options (int, optional): NORMALIZE_TOKEN_* bitmask. Defaults to
+///         NORMALIZE_DEFAULT_TOKEN_OPTIONS.
+///     skip_errors (bool): If True, a row that fails to expand is recorded
+///         in the returned error list and yields an empty list instead of
+///         aborting the whole batch. Defaults to False.
+///
+/// Returns:
+///     tuple[list[list[str]], list[tuple[int, str]]]: Expansions per row,
+///     and `(index, message)` pairs for rows that failed when `skip_errors`
+///     is set.
+///
+/// Raises:
+///     ValueError: If an address is empty or invalid and skip_errors is False
+///     RuntimeError: If libpostal encounters an error and skip_errors is False
+#[pyfunction]
+#[pyo3(signature = (addresses, languages=None, string_options=None, token_options=None, skip_errors=false))]
+pub fn expand_addresses(
+    py: Python<'_>,
+    addresses: Vec<String>,
+    languages: Option<Vec<String>>,
+    string_options: Option<u32>,
+    token_options: Option<u32>,
+    skip_errors: bool,
+) -> PyResult<(Vec<Vec<String>>, BatchErrors)> {
+    let languages = languages.unwrap_or_default();
+    let string_options = string_options.unwrap_or(NORMALIZE_DEFAULT_STRING_OPTIONS);
+    let token_options = token_options.unwrap_or(NORMALIZE_DEFAULT_TOKEN_OPTIONS);
+
+    py.allow_threads(|| {
+        runtime::with_lock(|| {
+            let mut results = Vec::with_capacity(addresses.len());
+            let mut errors = Vec::new();
+
+            for (index, address) in addresses.iter().enumerate() {
+                match expand_address_locked(address, &languages, string_options, token_options) {
+                    Ok(expanded) => results.push(expanded),
+                    Err(e) if skip_errors => {
+                        errors.push((index, e.to_string()));
+                        results.push(Vec::new());
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+
+            Ok((results, errors))
+        })
+    })
+    .map_err(PyErr::from)
+}
+
+/// Parse an address and map it onto the Google `type.PostalAddress` schema
+///
+/// Args:
+///     address (str): The address string to parse
+///     language (str, optional): ISO language code hint
+///     country (str, optional): ISO country code hint
+///
+/// Returns:
+///     dict: A dict with `region_code`, `postal_code`, `administrative_area`,
+///     `locality`, `sublocality`, `address_lines` (list[str]),
+///     `organization`, `recipients` (list[str]) and `language_code`
+///
+/// Raises:
+///     ValueError: If the address is empty or invalid
+///     RuntimeError: If libpostal encounters an error
+#[pyfunction]
+#[pyo3(signature = (address, language=None, country=None))]
+pub fn parse_address_to_postal_address(
+    py: Python<'_>,
+    address: &str,
+    language: Option<&str>,
+    country: Option<&str>,
+) -> PyResult<HashMap<String, PyObject>> {
+    let components = with_gil_released!(py, || parse_address_with_options(
+        address, language, country, ADDRESS_ALL
+    ))?;
+
+    let postal_address = postal_address::from_components(&components, language, country);
+
+    let mut result = HashMap::new();
+    result.insert("region_code".to_string(), postal_address.region_code.into_py(py));
+    result.insert("postal_code".to_string(), postal_address.postal_code.into_py(py));
+    result.insert(
+        "administrative_area".to_string(),
+        postal_address.administrative_area.into_py(py),
+    );
+    result.insert("locality".to_string(), postal_address.locality.into_py(py));
+    result.insert("sublocality".to_string(), postal_address.sublocality.into_py(py));
+    result.insert("address_lines".to_string(), postal_address.address_lines.into_py(py));
+    result.insert("organization".to_string(), postal_address.organization.into_py(py));
+    result.insert("recipients".to_string(), postal_address.recipients.into_py(py));
+    result.insert("language_code".to_string(), postal_address.language_code.into_py(py));
+
+    Ok(result)
+}
+
+/// Validate a components dict against the address rules for `country_code`
+///
+/// Args:
+///     components (dict): Address components keyed by the canonical field
+///         names (`name`, `organization`, `address_lines`, `locality`,
+///         `administrative_area`, `postal_code`, `sorting_code`,
+///         `dependent_locality`), each a single flat string (join multi-line
+///         `address_lines` with e.g. ", " before calling this)
+///     country_code (str): ISO country code the address claims to be in
+///
+/// Returns:
+///     list[tuple[str, str]]: `(field, problem)` pairs, where problem is one
+///     of `MISSING_REQUIRED_FIELD`, `UNEXPECTED_FIELD`, `UNKNOWN_VALUE` or
+///     `INVALID_FORMAT`. Empty if the address is valid. Countries with no
+///     bundled rule default to "anything allowed, nothing required".
+#[pyfunction]
+#[pyo3(signature = (components, country_code))]
+pub fn validate_address(
+    components: HashMap<String, String>,
+    country_code: &str,
+) -> Vec<(String, String)> {
+    validation::validate_address(&components, country_code)
+        .into_iter()
+        .map(|(field, problem)| (field.to_string(), problem.to_string()))
+        .collect()
+}
+
+/// Format a components dict into a country-ordered, multi-line postal
+/// address, the inverse of parsing
+///
+/// Args:
+///     components (dict): libpostal-style component labels (`house_number`,
+///         `road`, `city`, `state`, `postcode`, `country`, ...)
+///     country_code (str): ISO country code whose format conventions to use
+///
+/// Returns:
+///     str: The formatted address, lines joined with "\n". Countries with
+///     no bundled template fall back to a generic single-line
+///     `house_number road / city state postcode / country` layout, with the
+///     segments joined by " / ".
+#[pyfunction]
+#[pyo3(signature = (components, country_code))]
+pub fn format_address(components: HashMap<String, String>, country_code: &str) -> String {
+    formatting::format_address(&components, country_code)
+}
+
 /// Normalize an address by parsing and reconstructing it
 /// 
 /// Args:
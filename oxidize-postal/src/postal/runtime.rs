@@ -0,0 +1,90 @@
+//! Process-wide thread-safety guard for libpostal.
+//!
+//! `libpostal-sys` documents that libpostal itself is not thread-safe: every
+//! call must go through a single global lock. `with_lock` below is that
+//! lock, and every entry point into libpostal (`parse_address_string`,
+//! `expand_address_string`, the batch APIs, ...) goes through it so
+//! concurrent calls from multiple Python threads can't race inside
+//! libpostal.
+//!
+//! `libpostal_rust` performs its own lazy dictionary loading internally on
+//! first use and exposes no public init/teardown hook for this crate to
+//! drive. `setup()` forces that lazy load to happen immediately — useful to
+//! pre-warm dictionaries before spinning up worker threads — by running a
+//! harmless parse and expand under the lock. `teardown()` only resets this
+//! crate's own "have we warmed up" bookkeeping: it cannot free libpostal's
+//! already-loaded data, since libpostal-sys has no matching unload API, so
+//! that data stays resident for the process's lifetime and the next
+//! `setup()` call just re-runs the warm-up.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use libpostal_rust::{expand_address, parse_address, ExpandAddressOptions, ParseAddressOptions};
+use pyo3::prelude::*;
+
+/// Serializes every call into libpostal.
+static LIBPOSTAL_LOCK: Mutex<()> = Mutex::new(());
+
+/// Whether `setup()` has pre-warmed libpostal's lazily-loaded dictionaries.
+static WARMED: AtomicBool = AtomicBool::new(false);
+
+/// Acquire the process-wide lock and run `f` while holding it.
+pub fn with_lock<T, E>(f: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+    let _guard = LIBPOSTAL_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f()
+}
+
+/// Force libpostal's lazily-loaded dictionaries to load now instead of on
+/// the caller's first real parse/expand call. Safe to call more than once;
+/// later calls are a no-op until `teardown_runtime` is called.
+pub fn setup_runtime() {
+    if WARMED.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let _ = with_lock(|| {
+        if WARMED.load(Ordering::SeqCst) {
+            return Ok::<(), ()>(());
+        }
+        let _ = parse_address(" ", &ParseAddressOptions::default());
+        let _ = expand_address(" ", &ExpandAddressOptions::default());
+        WARMED.store(true, Ordering::SeqCst);
+        Ok::<(), ()>(())
+    });
+}
+
+/// Reset the "have we warmed up" bookkeeping so the next call re-enters
+/// `setup_runtime`. Does not unload libpostal's already-loaded data.
+pub fn teardown_runtime() {
+    WARMED.store(false, Ordering::SeqCst);
+}
+
+/// Whether `setup_runtime` has already pre-warmed libpostal.
+pub fn is_runtime_ready() -> bool {
+    WARMED.load(Ordering::SeqCst)
+}
+
+/// Pre-warm libpostal's lazily-loaded dictionaries now. Safe to call more
+/// than once; later calls are a no-op.
+#[pyfunction]
+pub fn setup() {
+    setup_runtime();
+}
+
+/// Reset the warm-up bookkeeping so the next `setup()` call runs the warm-up
+/// again. Parse/expand calls do not re-warm on their own. Does not free libpostal's already-loaded data — libpostal-sys
+/// has no matching unload API, so that data stays resident for the
+/// process's lifetime.
+#[pyfunction]
+pub fn teardown() {
+    teardown_runtime();
+}
+
+/// Whether `setup()` has already pre-warmed libpostal.
+#[pyfunction]
+pub fn is_ready() -> bool {
+    is_runtime_ready()
+}
@@ -0,0 +1,362 @@
+//! Country-aware address validation, modeled on libaddressinput's rule set.
+//!
+//! Each country has a rule describing which of the standard address fields
+//! it requires and allows: recipient Name (N), Organization (O), street
+//! Address lines (A), City/locality (C), administrative area/State (S),
+//! postal/Zip code (Z), sorting Code (X) and Dependent locality (D). The
+//! table below is a compact, offline subset of the chromium-i18n address
+//! metadata; countries not listed default to "anything allowed, nothing
+//! required".
+
+use std::collections::HashMap;
+
+/// The standard libaddressinput field set, named after the canonical keys
+/// callers are expected to pass in the `components` dict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressField {
+    Name,
+    Organization,
+    AddressLines,
+    Locality,
+    AdministrativeArea,
+    PostalCode,
+    SortingCode,
+    DependentLocality,
+}
+
+impl AddressField {
+    fn key(self) -> &'static str {
+        match self {
+            AddressField::Name => "name",
+            AddressField::Organization => "organization",
+            AddressField::AddressLines => "address_lines",
+            AddressField::Locality => "locality",
+            AddressField::AdministrativeArea => "administrative_area",
+            AddressField::PostalCode => "postal_code",
+            AddressField::SortingCode => "sorting_code",
+            AddressField::DependentLocality => "dependent_locality",
+        }
+    }
+}
+
+/// A validation problem found on a single field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationProblem {
+    MissingRequiredField,
+    UnexpectedField,
+    UnknownValue,
+    InvalidFormat,
+}
+
+impl ValidationProblem {
+    fn as_str(self) -> &'static str {
+        match self {
+            ValidationProblem::MissingRequiredField => "MISSING_REQUIRED_FIELD",
+            ValidationProblem::UnexpectedField => "UNEXPECTED_FIELD",
+            ValidationProblem::UnknownValue => "UNKNOWN_VALUE",
+            ValidationProblem::InvalidFormat => "INVALID_FORMAT",
+        }
+    }
+}
+
+use AddressField::*;
+
+const ALL_FIELDS: [AddressField; 8] = [
+    Name,
+    Organization,
+    AddressLines,
+    Locality,
+    AdministrativeArea,
+    PostalCode,
+    SortingCode,
+    DependentLocality,
+];
+
+const US_STATES: &[&str] = &[
+    "AL", "AK", "AZ", "AR", "CA", "CO", "CT", "DE", "FL", "GA", "HI", "ID", "IL", "IN", "IA", "KS",
+    "KY", "LA", "ME", "MD", "MA", "MI", "MN", "MS", "MO", "MT", "NE", "NV", "NH", "NJ", "NM", "NY",
+    "NC", "ND", "OH", "OK", "OR", "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VT", "VA", "WA", "WV",
+    "WI", "WY", "DC",
+];
+
+const CA_PROVINCES: &[&str] = &[
+    "AB", "BC", "MB", "NB", "NL", "NS", "NT", "NU", "ON", "PE", "QC", "SK", "YT",
+];
+
+const AU_STATES: &[&str] = &["ACT", "NSW", "NT", "QLD", "SA", "TAS", "VIC", "WA"];
+
+const JP_PREFECTURES: &[&str] = &[
+    "Hokkaido", "Aomori", "Iwate", "Miyagi", "Akita", "Yamagata", "Fukushima", "Ibaraki",
+    "Tochigi", "Gunma", "Saitama", "Chiba", "Tokyo", "Kanagawa", "Niigata", "Toyama", "Ishikawa",
+    "Fukui", "Yamanashi", "Nagano", "Gifu", "Shizuoka", "Aichi", "Mie", "Shiga", "Kyoto", "Osaka",
+    "Hyogo", "Nara", "Wakayama", "Tottori", "Shimane", "Okayama", "Hiroshima", "Yamaguchi",
+    "Tokushima", "Kagawa", "Ehime", "Kochi", "Fukuoka", "Saga", "Nagasaki", "Kumamoto", "Oita",
+    "Miyazaki", "Kagoshima", "Okinawa",
+];
+
+struct CountryRule {
+    required: &'static [AddressField],
+    allowed: &'static [AddressField],
+    known_administrative_areas: Option<&'static [&'static str]>,
+    postal_code_validator: Option<fn(&str) -> bool>,
+}
+
+const DEFAULT_RULE: CountryRule = CountryRule {
+    required: &[],
+    allowed: &ALL_FIELDS,
+    known_administrative_areas: None,
+    postal_code_validator: None,
+};
+
+fn is_us_zip(postal_code: &str) -> bool {
+    let digits: Vec<char> = postal_code.chars().filter(|c| *c != '-').collect();
+    (digits.len() == 5 || digits.len() == 9) && digits.iter().all(|c| c.is_ascii_digit())
+}
+
+fn is_ca_postal_code(postal_code: &str) -> bool {
+    let chars: Vec<char> = postal_code.chars().filter(|c| !c.is_whitespace()).collect();
+    chars.len() == 6
+        && chars[0].is_ascii_alphabetic()
+        && chars[1].is_ascii_digit()
+        && chars[2].is_ascii_alphabetic()
+        && chars[3].is_ascii_digit()
+        && chars[4].is_ascii_alphabetic()
+        && chars[5].is_ascii_digit()
+}
+
+fn is_gb_postcode(postal_code: &str) -> bool {
+    let chars: Vec<char> = postal_code.chars().filter(|c| !c.is_whitespace()).collect();
+    // UK postcodes end in digit + letter + letter (e.g. "SW1A 1AA"), so the
+    // digit sits three characters from the end, not two.
+    (5..=7).contains(&chars.len())
+        && chars.first().is_some_and(char::is_ascii_alphabetic)
+        && chars.last().is_some_and(char::is_ascii_alphabetic)
+        && chars[chars.len() - 3].is_ascii_digit()
+}
+
+fn is_five_digit_code(postal_code: &str) -> bool {
+    postal_code.len() == 5 && postal_code.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_jp_postal_code(postal_code: &str) -> bool {
+    let digits: Vec<char> = postal_code.chars().filter(|c| *c != '-').collect();
+    digits.len() == 7 && digits.iter().all(|c| c.is_ascii_digit())
+}
+
+fn is_four_digit_code(postal_code: &str) -> bool {
+    postal_code.len() == 4 && postal_code.chars().all(|c| c.is_ascii_digit())
+}
+
+fn country_rule(country_code: &str) -> CountryRule {
+    match country_code.to_uppercase().as_str() {
+        "US" => CountryRule {
+            required: &[AddressLines, Locality, AdministrativeArea, PostalCode],
+            allowed: &[Name, Organization, AddressLines, Locality, AdministrativeArea, PostalCode],
+            known_administrative_areas: Some(US_STATES),
+            postal_code_validator: Some(is_us_zip),
+        },
+        "CA" => CountryRule {
+            required: &[AddressLines, Locality, AdministrativeArea, PostalCode],
+            allowed: &[Name, Organization, AddressLines, Locality, AdministrativeArea, PostalCode],
+            known_administrative_areas: Some(CA_PROVINCES),
+            postal_code_validator: Some(is_ca_postal_code),
+        },
+        "GB" => CountryRule {
+            required: &[AddressLines, Locality, PostalCode],
+            allowed: &[Name, Organization, AddressLines, Locality, PostalCode],
+            known_administrative_areas: None,
+            postal_code_validator: Some(is_gb_postcode),
+        },
+        "DE" => CountryRule {
+            required: &[AddressLines, Locality, PostalCode],
+            allowed: &[Name, Organization, AddressLines, Locality, PostalCode],
+            known_administrative_areas: None,
+            postal_code_validator: Some(is_five_digit_code),
+        },
+        "FR" => CountryRule {
+            required: &[AddressLines, Locality, PostalCode],
+            allowed: &[Name, Organization, AddressLines, Locality, PostalCode],
+            known_administrative_areas: None,
+            postal_code_validator: Some(is_five_digit_code),
+        },
+        "JP" => CountryRule {
+            required: &[AddressLines, Locality, AdministrativeArea, PostalCode],
+            allowed: &[Name, Organization, AddressLines, Locality, AdministrativeArea, PostalCode],
+            known_administrative_areas: Some(JP_PREFECTURES),
+            postal_code_validator: Some(is_jp_postal_code),
+        },
+        "AU" => CountryRule {
+            required: &[AddressLines, Locality, AdministrativeArea, PostalCode],
+            allowed: &[Name, Organization, AddressLines, Locality, AdministrativeArea, PostalCode],
+            known_administrative_areas: Some(AU_STATES),
+            postal_code_validator: Some(is_four_digit_code),
+        },
+        _ => DEFAULT_RULE,
+    }
+}
+
+fn is_present(components: &HashMap<String, String>, field: AddressField) -> bool {
+    components
+        .get(field.key())
+        .is_some_and(|value| !value.trim().is_empty())
+}
+
+/// Validate a components dict against the rules for `country_code`,
+/// returning `(field, problem)` pairs. Unknown country codes default to
+/// "anything allowed, nothing required" with no postal-code pattern and no
+/// known administrative areas, so they never produce any problems.
+pub fn validate_address(
+    components: &HashMap<String, String>,
+    country_code: &str,
+) -> Vec<(&'static str, &'static str)> {
+    let rule = country_rule(country_code);
+    let mut problems = Vec::new();
+
+    for field in rule.required {
+        if !is_present(components, *field) {
+            problems.push((field.key(), ValidationProblem::MissingRequiredField.as_str()));
+        }
+    }
+
+    for field in ALL_FIELDS {
+        if is_present(components, field) && !rule.allowed.contains(&field) {
+            problems.push((field.key(), ValidationProblem::UnexpectedField.as_str()));
+        }
+    }
+
+    if let Some(known_areas) = rule.known_administrative_areas {
+        if let Some(value) = components.get(AdministrativeArea.key()) {
+            if !value.trim().is_empty()
+                && !known_areas
+                    .iter()
+                    .any(|area| area.eq_ignore_ascii_case(value.trim()))
+            {
+                problems.push((
+                    AdministrativeArea.key(),
+                    ValidationProblem::UnknownValue.as_str(),
+                ));
+            }
+        }
+    }
+
+    if let Some(validator) = rule.postal_code_validator {
+        if let Some(value) = components.get(PostalCode.key()) {
+            if !value.trim().is_empty() && !validator(value.trim()) {
+                problems.push((PostalCode.key(), ValidationProblem::InvalidFormat.as_str()));
+            }
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn components(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn us_address_requires_locality_state_and_postal_code() {
+        let problems = validate_address(&components(&[("address_lines", "1600 Amphitheatre Pkwy")]), "US");
+        let missing: Vec<&str> = problems
+            .iter()
+            .filter(|(_, problem)| *problem == "MISSING_REQUIRED_FIELD")
+            .map(|(field, _)| *field)
+            .collect();
+
+        assert!(missing.contains(&"locality"));
+        assert!(missing.contains(&"administrative_area"));
+        assert!(missing.contains(&"postal_code"));
+    }
+
+    #[test]
+    fn us_address_rejects_unknown_state() {
+        let problems = validate_address(
+            &components(&[
+                ("address_lines", "1600 Amphitheatre Pkwy"),
+                ("locality", "Mountain View"),
+                ("administrative_area", "ZZ"),
+                ("postal_code", "94043"),
+            ]),
+            "US",
+        );
+
+        assert!(problems.contains(&("administrative_area", "UNKNOWN_VALUE")));
+    }
+
+    #[test]
+    fn gb_address_has_no_administrative_area_requirement() {
+        let problems = validate_address(
+            &components(&[
+                ("address_lines", "10 Downing Street"),
+                ("locality", "London"),
+                ("postal_code", "SW1A 2AA"),
+            ]),
+            "GB",
+        );
+
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn unknown_country_code_never_reports_missing_fields() {
+        let problems = validate_address(&components(&[]), "ZZ");
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn us_zip_validator_accepts_five_and_nine_digit_forms() {
+        assert!(is_us_zip("94043"));
+        assert!(is_us_zip("94043-1351"));
+        assert!(!is_us_zip("9404"));
+        assert!(!is_us_zip("ABCDE"));
+    }
+
+    #[test]
+    fn ca_postal_code_validator_requires_alternating_letters_and_digits() {
+        assert!(is_ca_postal_code("K1A 0B1"));
+        assert!(is_ca_postal_code("K1A0B1"));
+        assert!(!is_ca_postal_code("K1A 0BB"));
+    }
+
+    #[test]
+    fn gb_postcode_validator_accepts_real_postcodes() {
+        for postcode in ["SW1A 1AA", "M1 1AE", "EC1A 1BB", "CR2 6XH", "DN55 1PT"] {
+            assert!(is_gb_postcode(postcode), "expected {postcode} to be valid");
+        }
+    }
+
+    #[test]
+    fn gb_postcode_validator_rejects_malformed_codes() {
+        assert!(!is_gb_postcode("SW1A"));
+        assert!(!is_gb_postcode("12345"));
+    }
+
+    #[test]
+    fn jp_postal_code_validator_accepts_seven_digits_with_optional_hyphen() {
+        assert!(is_jp_postal_code("100-0001"));
+        assert!(is_jp_postal_code("1000001"));
+        assert!(!is_jp_postal_code("100-001"));
+    }
+
+    #[test]
+    fn invalid_us_postal_code_is_reported_as_invalid_format() {
+        let problems = validate_address(
+            &components(&[
+                ("address_lines", "1600 Amphitheatre Pkwy"),
+                ("locality", "Mountain View"),
+                ("administrative_area", "CA"),
+                ("postal_code", "not-a-zip"),
+            ]),
+            "US",
+        );
+
+        assert!(problems.contains(&("postal_code", "INVALID_FORMAT")));
+    }
+}